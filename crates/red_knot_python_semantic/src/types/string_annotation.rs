@@ -1,8 +1,8 @@
 use ruff_db::source::source_text;
 use ruff_python_ast::str::raw_contents;
 use ruff_python_ast::{ModExpression, StringFlags};
-use ruff_python_parser::{parse_expression_range, Parsed};
-use ruff_text_size::Ranged;
+use ruff_python_parser::{parse_expression, parse_expression_range, Parsed};
+use ruff_text_size::{Ranged, TextRange, TextSize};
 
 use salsa::plumbing::AsId;
 
@@ -10,7 +10,71 @@ use crate::semantic_index::expression::Expression;
 use crate::types::diagnostic::{TypeCheckDiagnostics, TypeCheckDiagnosticsBuilder};
 use crate::Db;
 
-type AnnotationParseResult = Result<Parsed<ModExpression>, TypeCheckDiagnostics>;
+type AnnotationParseResult = Result<ParsedAnnotation, TypeCheckDiagnostics>;
+
+/// The parsed expression of a string annotation, together with enough information to translate
+/// its ranges back into the original source.
+///
+/// Most string annotations parse directly out of the source (the quoted text is exactly the
+/// expression we want to check), so their ranges already point at the right place. But an
+/// annotation containing an escaped quote, e.g. `"Callable[[\"A\"], B]"`, has to be unescaped
+/// before it can be parsed, which means the parsed expression's ranges refer to offsets in the
+/// *decoded* string rather than the original file. [`ParsedAnnotation::source_range`] maps a
+/// decoded range back to the matching range in the source so that diagnostics on the parsed
+/// expression still point inside the original literal.
+pub(crate) struct ParsedAnnotation {
+    parsed: Parsed<ModExpression>,
+    /// The offset of the start of the decoded contents within the source, and a table of
+    /// offsets in the decoded string paired with the corresponding offset in the original
+    /// source, recorded at every point where an escape sequence caused the decoded string to
+    /// diverge from the source. `None` when the annotation didn't need unescaping, in which case
+    /// the parsed expression's ranges are already in source coordinates.
+    backward_offsets: Option<(TextSize, Vec<(TextSize, TextSize)>)>,
+}
+
+impl ParsedAnnotation {
+    /// Returns the parsed expression tree.
+    ///
+    /// # Warning
+    ///
+    /// The ranges on nodes in this tree are in *decoded-string* coordinates, not source
+    /// coordinates, whenever this annotation required unescaping (see the struct docs). Callers
+    /// that turn a range from this tree into a diagnostic, or otherwise use it to index into the
+    /// original source, must first translate it with [`ParsedAnnotation::source_range`]; slicing
+    /// the source directly with a range from here will silently mis-locate the result for any
+    /// annotation containing an escaped quote.
+    pub(crate) fn parsed_in_decoded_coordinates(&self) -> &Parsed<ModExpression> {
+        &self.parsed
+    }
+
+    /// Translates a range produced by parsing the decoded annotation (i.e. a range taken from
+    /// [`ParsedAnnotation::parsed_in_decoded_coordinates`]) back into the original source,
+    /// accounting for any escape sequences that were unescaped before parsing.
+    pub(crate) fn source_range(&self, range: TextRange) -> TextRange {
+        let Some((raw_start, offsets)) = &self.backward_offsets else {
+            return range;
+        };
+        TextRange::new(
+            Self::source_offset(offsets, *raw_start, range.start()),
+            Self::source_offset(offsets, *raw_start, range.end()),
+        )
+    }
+
+    fn source_offset(
+        offsets: &[(TextSize, TextSize)],
+        raw_start: TextSize,
+        decoded_offset: TextSize,
+    ) -> TextSize {
+        match offsets.binary_search_by_key(&decoded_offset, |(decoded, _)| *decoded) {
+            Ok(index) => offsets[index].1,
+            Err(0) => raw_start + decoded_offset,
+            Err(index) => {
+                let (decoded, source) = offsets[index - 1];
+                source + (decoded_offset - decoded)
+            }
+        }
+    }
+}
 
 /// Parses the given expression as a string annotation.
 ///
@@ -46,32 +110,56 @@ pub(crate) fn parse_string_annotation<'db>(
             );
         }
 
+        let range_excluding_quotes = string_literal
+            .range()
+            .add_start(string_literal.flags.opener_len())
+            .sub_end(string_literal.flags.closer_len());
+
         // Compare the raw contents (without quotes) of the expression with the parsed contents
         // contained in the string literal.
-        if raw_contents(node_text)
-            .is_some_and(|raw_contents| raw_contents == string_literal.as_str())
-        {
-            let range_excluding_quotes = string_literal
-                .range()
-                .add_start(string_literal.flags.opener_len())
-                .sub_end(string_literal.flags.closer_len());
-
-            match parse_expression_range(source.as_str(), range_excluding_quotes) {
-                Ok(parsed) => return Ok(parsed),
-                Err(parse_error) => diagnostics.add(
-                    string_literal.into(),
-                    "forward-annotation-syntax-error",
-                    format_args!("Syntax error in forward annotation: {}", parse_error.error),
-                ),
+        match raw_contents(node_text) {
+            Some(raw) if raw == string_literal.as_str() => {
+                match parse_expression_range(source.as_str(), range_excluding_quotes) {
+                    Ok(parsed) => {
+                        return Ok(ParsedAnnotation {
+                            parsed,
+                            backward_offsets: None,
+                        })
+                    }
+                    Err(parse_error) => diagnostics.add(
+                        string_literal.into(),
+                        "forward-annotation-syntax-error",
+                        format_args!("Syntax error in forward annotation: {}", parse_error.error),
+                    ),
+                }
             }
-        } else {
-            // The raw contents of the string doesn't match the parsed content. This could be the
-            // case for annotations that contain escaped quotes.
-            diagnostics.add(
+            Some(raw) => {
+                // The raw contents of the string don't match the parsed contents, most likely
+                // because the annotation contains an escaped quote (e.g. `"Callable[[\"A\"],
+                // B]"`). Parse the string's already-decoded value (`string_literal.as_str()`)
+                // instead of rejecting it outright, keeping a map back to the source so
+                // diagnostics on the parsed expression still land inside the original literal.
+                let decoded = string_literal.as_str();
+                let offsets = backward_offsets(raw, decoded, range_excluding_quotes.start());
+                match parse_expression(decoded) {
+                    Ok(parsed) => {
+                        return Ok(ParsedAnnotation {
+                            parsed,
+                            backward_offsets: Some((range_excluding_quotes.start(), offsets)),
+                        })
+                    }
+                    Err(parse_error) => diagnostics.add(
+                        string_literal.into(),
+                        "forward-annotation-syntax-error",
+                        format_args!("Syntax error in forward annotation: {}", parse_error.error),
+                    ),
+                }
+            }
+            None => diagnostics.add(
                 string_expr.into(),
                 "annotation-escape-character",
                 format_args!("Type expressions cannot contain escape characters"),
-            );
+            ),
         }
     } else {
         // String is implicitly concatenated.
@@ -84,3 +172,264 @@ pub(crate) fn parse_string_annotation<'db>(
 
     Err(diagnostics.finish())
 }
+
+/// Builds a table mapping offsets in `decoded` back to offsets in `raw` (the source spelling of
+/// the same literal, escape sequences un-decoded), for use by [`ParsedAnnotation::source_range`].
+///
+/// `raw` and `decoded` are walked in lockstep; ordinary characters don't need an entry in the
+/// table, since their offset in `decoded` is always the same distance from the nearest preceding
+/// entry as it is in `raw`. An entry is recorded after every escape sequence whose decoded form
+/// differs from its source spelling (e.g. `\"` decoding to a single `"`), so that
+/// `source_offset`'s binary search can resync at that point.
+///
+/// `raw_start` is the offset of the start of `raw` within the source.
+fn backward_offsets(raw: &str, decoded: &str, raw_start: TextSize) -> Vec<(TextSize, TextSize)> {
+    let mut offsets = Vec::new();
+
+    let mut raw_pos = 0usize;
+    let mut decoded_pos = 0usize;
+
+    while raw_pos < raw.len() {
+        if raw.as_bytes()[raw_pos] != b'\\' {
+            let len = char_len_at(raw, raw_pos);
+            raw_pos += len;
+            decoded_pos += len;
+            continue;
+        }
+
+        let (escape_len, outcome) = escape_sequence_len(&raw[raw_pos..]);
+        raw_pos += escape_len;
+
+        match outcome {
+            // An escape sequence that Python doesn't decode (e.g. the unrecognized escape `\q`,
+            // which is kept as a literal backslash followed by the character): raw and decoded
+            // agree here, so there's no divergence to record. Note that this can't be detected
+            // by comparing `raw`'s and `decoded`'s bytes at this position, since a decoded escape
+            // can coincidentally produce the same bytes as a following verbatim one (e.g. two
+            // consecutive `\\` escapes both decode to a single `\`, which also happens to be the
+            // first byte of `\q`).
+            EscapeOutcome::Verbatim => {
+                decoded_pos += escape_len;
+                continue;
+            }
+            // A line continuation (`\` immediately followed by a newline) decodes to nothing.
+            EscapeOutcome::Empty => {}
+            EscapeOutcome::SingleChar => {
+                decoded_pos += char_len_at(decoded, decoded_pos);
+            }
+        }
+
+        offsets.push((
+            TextSize::try_from(decoded_pos).unwrap(),
+            raw_start + TextSize::try_from(raw_pos).unwrap(),
+        ));
+    }
+
+    offsets
+}
+
+/// Returns the length, in bytes, of the character starting at byte offset `byte_pos` in `s`.
+fn char_len_at(s: &str, byte_pos: usize) -> usize {
+    s[byte_pos..].chars().next().map_or(0, char::len_utf8)
+}
+
+/// What an escape sequence decodes to, for the purposes of walking `raw` and `decoded` in
+/// lockstep in [`backward_offsets`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum EscapeOutcome {
+    /// Decodes to nothing (a line continuation).
+    Empty,
+    /// Decodes to exactly one character.
+    SingleChar,
+    /// Not decoded at all: Python keeps the escape's source spelling verbatim (e.g. the
+    /// unrecognized escape `\q`), so `raw` and `decoded` agree over this span.
+    Verbatim,
+}
+
+/// Returns the length, in bytes, of the escape sequence at the start of `text` (which must begin
+/// with a `\`), including the leading backslash, along with what it decodes to.
+///
+/// Assumes `text` comes from a string literal that the parser has already accepted, so every
+/// escape sequence is well-formed (e.g. `\x` is always followed by exactly two hex digits).
+fn escape_sequence_len(text: &str) -> (usize, EscapeOutcome) {
+    let mut chars = text.char_indices();
+    debug_assert_eq!(chars.next().map(|(_, c)| c), Some('\\'));
+    let Some((_, kind)) = chars.next() else {
+        return (1, EscapeOutcome::Verbatim);
+    };
+
+    match kind {
+        '\n' => (2, EscapeOutcome::Empty),
+        '\r' if text.as_bytes().get(2) == Some(&b'\n') => (3, EscapeOutcome::Empty),
+        '\r' => (2, EscapeOutcome::Empty),
+        '\\' | '\'' | '"' | 'a' | 'b' | 'f' | 'n' | 'r' | 't' | 'v' => {
+            (2, EscapeOutcome::SingleChar)
+        }
+        '0'..='7' => {
+            let mut len = 2;
+            for (_, c) in chars.take(2) {
+                if ('0'..='7').contains(&c) {
+                    len += 1;
+                } else {
+                    break;
+                }
+            }
+            (len, EscapeOutcome::SingleChar)
+        }
+        'x' => (4, EscapeOutcome::SingleChar),
+        'u' => (6, EscapeOutcome::SingleChar),
+        'U' => (10, EscapeOutcome::SingleChar),
+        'N' if text.as_bytes().get(2) == Some(&b'{') => {
+            let len = text[2..]
+                .find('}')
+                .map_or(2, |relative_end| 2 + relative_end + 1);
+            (len, EscapeOutcome::SingleChar)
+        }
+        'N' => (2, EscapeOutcome::Verbatim),
+        // An unrecognized escape (e.g. `\q`): Python keeps the backslash and the character
+        // as-is, which may itself be a multi-byte Unicode scalar.
+        _ => (1 + kind.len_utf8(), EscapeOutcome::Verbatim),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{backward_offsets, escape_sequence_len, EscapeOutcome, ParsedAnnotation};
+    use ruff_text_size::TextSize;
+
+    #[test]
+    fn escape_sequence_len_simple_escapes() {
+        assert_eq!(escape_sequence_len(r"\\rest"), (2, EscapeOutcome::SingleChar));
+        assert_eq!(escape_sequence_len("\\\"rest"), (2, EscapeOutcome::SingleChar));
+        assert_eq!(escape_sequence_len("\\'rest"), (2, EscapeOutcome::SingleChar));
+        assert_eq!(escape_sequence_len("\\nrest"), (2, EscapeOutcome::SingleChar));
+        assert_eq!(escape_sequence_len("\\trest"), (2, EscapeOutcome::SingleChar));
+    }
+
+    #[test]
+    fn escape_sequence_len_line_continuation() {
+        assert_eq!(escape_sequence_len("\\\nrest"), (2, EscapeOutcome::Empty));
+    }
+
+    #[test]
+    fn escape_sequence_len_hex_and_unicode_escapes() {
+        assert_eq!(escape_sequence_len(r"\x41rest"), (4, EscapeOutcome::SingleChar));
+        assert_eq!(escape_sequence_len("\\u0041rest"), (6, EscapeOutcome::SingleChar));
+        assert_eq!(escape_sequence_len(r"\U00000041rest"), (10, EscapeOutcome::SingleChar));
+    }
+
+    #[test]
+    fn escape_sequence_len_octal_escape() {
+        assert_eq!(escape_sequence_len(r"\101rest"), (4, EscapeOutcome::SingleChar));
+        // Octal escapes are at most 3 digits long.
+        assert_eq!(escape_sequence_len(r"\1234"), (4, EscapeOutcome::SingleChar));
+        assert_eq!(escape_sequence_len(r"\1rest"), (2, EscapeOutcome::SingleChar));
+    }
+
+    #[test]
+    fn escape_sequence_len_named_escape() {
+        assert_eq!(
+            escape_sequence_len(r"\N{BULLET}rest"),
+            ("\\N{BULLET}".len(), EscapeOutcome::SingleChar),
+        );
+    }
+
+    #[test]
+    fn escape_sequence_len_named_escape_requires_immediate_brace() {
+        // `\N` not immediately followed by `{` isn't a named escape. Falling back to the
+        // ordinary 2-byte case (rather than scanning ahead for any `{`/`}` pair) keeps an
+        // unrelated brace later in the same literal, e.g. a nested `Literal['{...}']`, from
+        // desyncing the offset table.
+        assert_eq!(
+            escape_sequence_len(r"\N is not a name {with a brace} later"),
+            (2, EscapeOutcome::Verbatim),
+        );
+    }
+
+    #[test]
+    fn escape_sequence_len_unrecognized_escape() {
+        assert_eq!(escape_sequence_len(r"\qrest"), (2, EscapeOutcome::Verbatim));
+    }
+
+    #[test]
+    fn escape_sequence_len_multi_byte_unrecognized_escape() {
+        let text = "\\ürest";
+        let (len, outcome) = escape_sequence_len(text);
+        assert_eq!(len, 1 + 'ü'.len_utf8());
+        assert_eq!(outcome, EscapeOutcome::Verbatim);
+        // Must land on a char boundary, or slicing `raw` with this length would panic.
+        assert!(text.is_char_boundary(len));
+    }
+
+    #[test]
+    fn backward_offsets_maps_escaped_quotes_back_to_source() {
+        // Source: `"Callable[[\"A\"], B]"` (the annotation's contents, quotes excluded).
+        let raw = "Callable[[\\\"A\\\"], B]";
+        // The parser's decoded value for the same contents.
+        let decoded = "Callable[[\"A\"], B]";
+        let raw_start = TextSize::new(10);
+
+        let offsets = backward_offsets(raw, decoded, raw_start);
+        assert_eq!(
+            offsets,
+            vec![
+                (TextSize::new(11), TextSize::new(22)),
+                (TextSize::new(13), TextSize::new(25)),
+            ]
+        );
+
+        // Before the first escape, decoded and source offsets move in lockstep.
+        assert_eq!(
+            ParsedAnnotation::source_offset(&offsets, raw_start, TextSize::new(0)),
+            raw_start,
+        );
+        // Exactly at a recorded resync point.
+        assert_eq!(
+            ParsedAnnotation::source_offset(&offsets, raw_start, TextSize::new(11)),
+            TextSize::new(22),
+        );
+        // Past the last recorded point, offsets still translate using the final entry.
+        assert_eq!(
+            ParsedAnnotation::source_offset(&offsets, raw_start, TextSize::new(18)),
+            TextSize::new(30),
+        );
+    }
+
+    #[test]
+    fn backward_offsets_empty_when_nothing_diverges() {
+        assert_eq!(
+            backward_offsets("no escapes here", "no escapes here", TextSize::new(0)),
+            Vec::new(),
+        );
+    }
+
+    #[test]
+    fn backward_offsets_consecutive_escapes_that_coincide_with_raw_bytes() {
+        // Two consecutive `\\` escapes each decode to a single `\`, which happens to match the
+        // raw spelling of both this escape and the next one. A naive "does the decoded output
+        // start with the escape's own raw text" check can walk past both as if neither diverged;
+        // each must still get its own resync point.
+        let raw = r"\\\\";
+        let decoded = "\\\\";
+        let raw_start = TextSize::new(0);
+
+        let offsets = backward_offsets(raw, decoded, raw_start);
+        assert_eq!(
+            offsets,
+            vec![(TextSize::new(1), TextSize::new(2)), (TextSize::new(2), TextSize::new(4))],
+        );
+    }
+
+    #[test]
+    fn backward_offsets_escape_followed_by_unrecognized_escape() {
+        // `\\` decodes to `\`, which coincides with the raw spelling of the following
+        // unrecognized escape `\q`. The resync point for the first escape must still be
+        // recorded even though the second escape is verbatim.
+        let raw = r"\\\q";
+        let decoded = "\\\\q";
+        let raw_start = TextSize::new(0);
+
+        let offsets = backward_offsets(raw, decoded, raw_start);
+        assert_eq!(offsets, vec![(TextSize::new(1), TextSize::new(2))]);
+    }
+}
@@ -0,0 +1,72 @@
+use ruff_diagnostics::{Edit, Fix};
+use ruff_python_ast::{Expr, ExprCall};
+use ruff_text_size::Ranged;
+
+use crate::checkers::ast::Checker;
+use crate::importer::ImportRequest;
+
+/// Builds a fix that rewrites a single-argument `os.path.<func>(arg)` call into
+/// `Path(arg).<method>().<attribute>`, importing `Path` from `pathlib` (or reusing an existing
+/// `pathlib` import or alias) if it isn't already available.
+///
+/// Declines the fix (returning `None`) when the call's argument isn't a single, non-starred,
+/// non-keyword expression, since there's no way to map e.g. `os.path.getatime(*paths)` or
+/// `os.path.getatime(path=p)` onto a single `Path(...)` constructor argument.
+pub(crate) fn fix_os_path_call(
+    checker: &Checker,
+    call: &ExprCall,
+    method: &str,
+    attribute: &str,
+) -> Option<Fix> {
+    let [argument] = &*call.arguments.args else {
+        return None;
+    };
+    if !call.arguments.keywords.is_empty() {
+        return None;
+    }
+    if matches!(argument, Expr::Starred(_)) {
+        return None;
+    }
+
+    let (import_edit, binding) = checker
+        .importer()
+        .get_or_import_symbol(
+            &ImportRequest::import("pathlib", "Path"),
+            call.start(),
+            checker.semantic(),
+        )
+        .ok()?;
+
+    let argument_text = checker.locator().slice(argument.range());
+    let argument_source = if is_trivially_parenthesizable(argument) {
+        argument_text.to_string()
+    } else {
+        format!("({argument_text})")
+    };
+
+    let call_edit = Edit::range_replacement(
+        format!("{binding}({argument_source}).{method}().{attribute}"),
+        call.range(),
+    );
+
+    Some(Fix::safe_edits(call_edit, [import_edit]))
+}
+
+/// Returns `true` if `expr` can be written as the sole argument to a call without needing to be
+/// wrapped in parentheses first, e.g. a name, attribute access, call, or literal.
+fn is_trivially_parenthesizable(expr: &Expr) -> bool {
+    matches!(
+        expr,
+        Expr::Name(_)
+            | Expr::Attribute(_)
+            | Expr::Call(_)
+            | Expr::Subscript(_)
+            | Expr::StringLiteral(_)
+            | Expr::FString(_)
+            | Expr::NumberLiteral(_)
+            | Expr::List(_)
+            | Expr::Tuple(_)
+            | Expr::Dict(_)
+            | Expr::Set(_)
+    )
+}
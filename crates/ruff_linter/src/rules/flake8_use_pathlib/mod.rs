@@ -0,0 +1,2 @@
+pub(crate) mod helpers;
+pub(crate) mod rules;
@@ -0,0 +1,3 @@
+pub(crate) use os_path_getatime::*;
+
+mod os_path_getatime;
@@ -1,5 +1,10 @@
-use ruff_diagnostics::Violation;
+use ruff_diagnostics::{Diagnostic, Edit, Fix, FixAvailability, Violation};
 use ruff_macros::{derive_message_formats, violation};
+use ruff_python_ast::ExprCall;
+use ruff_text_size::Ranged;
+
+use crate::checkers::ast::Checker;
+use crate::rules::flake8_use_pathlib::helpers::fix_os_path_call;
 
 /// ## What it does
 /// Checks for uses of `os.path.getatime`.
@@ -40,8 +45,25 @@ use ruff_macros::{derive_message_formats, violation};
 pub struct OsPathGetatime;
 
 impl Violation for OsPathGetatime {
+    const FIX_AVAILABILITY: FixAvailability = FixAvailability::Sometimes(
+        "Requires a single, non-starred, non-keyword argument",
+    );
+
     #[derive_message_formats]
     fn message(&self) -> String {
         "`os.path.getatime` should be replaced by `Path.stat().st_atime`".to_string()
     }
+
+    fn fix_title(&self) -> Option<String> {
+        Some("Replace with `Path(...).stat().st_atime`".to_string())
+    }
+}
+
+/// PTH204
+pub(crate) fn os_path_getatime(checker: &mut Checker, call: &ExprCall) {
+    let mut diagnostic = Diagnostic::new(OsPathGetatime, call.range());
+    if let Some(fix) = fix_os_path_call(checker, call, "stat", "st_atime") {
+        diagnostic.set_fix(fix);
+    }
+    checker.diagnostics.push(diagnostic);
 }
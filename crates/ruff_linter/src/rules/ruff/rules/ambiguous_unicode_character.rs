@@ -0,0 +1,232 @@
+use rustc_hash::FxHashSet;
+
+use ruff_diagnostics::{AlwaysFixableViolation, Diagnostic, Edit, Fix};
+use ruff_macros::{derive_message_formats, violation};
+use ruff_source_file::Locator;
+use ruff_text_size::{TextLen, TextRange, TextSize};
+
+use super::confusables::confusable;
+
+/// ## What it does
+/// Checks for ambiguous Unicode characters in string literals.
+///
+/// ## Why is this bad?
+/// Some Unicode characters are visually similar to ASCII characters, but are
+/// not equivalent. For example, `GREEK QUESTION MARK` (U+037E) is often
+/// indistinguishable from a semicolon (`;`) in most fonts, but will raise a
+/// `SyntaxError` if used in Python code outside of a string.
+///
+/// This rule flags usages of Unicode characters that are confusable with
+/// other, more common characters, so that the offending character can be
+/// replaced with its unambiguous counterpart.
+///
+/// ## Example
+/// ```python
+/// x = "dir1；dir2"  # Looks like a semicolon, but is actually `FULLWIDTH SEMICOLON` (U+FF1B).
+/// ```
+///
+/// Use instead:
+/// ```python
+/// x = "dir1;dir2"  # Now uses an ASCII semicolon.
+/// ```
+#[violation]
+pub struct AmbiguousUnicodeCharacterString {
+    confusable: char,
+    representant: char,
+    name: &'static str,
+}
+
+impl AlwaysFixableViolation for AmbiguousUnicodeCharacterString {
+    #[derive_message_formats]
+    fn message(&self) -> String {
+        let AmbiguousUnicodeCharacterString {
+            confusable,
+            representant,
+            name,
+        } = self;
+        format!(
+            "Ambiguous unicode character '{name}' (U+{:04X}) looks like '{representant}'",
+            *confusable as u32,
+        )
+    }
+
+    fn fix_title(&self) -> String {
+        let AmbiguousUnicodeCharacterString { representant, .. } = self;
+        format!("Replace with '{representant}'")
+    }
+}
+
+/// ## What it does
+/// Checks for ambiguous Unicode characters in identifiers (function, class,
+/// and variable names).
+///
+/// ## Why is this bad?
+/// An identifier that looks like an ASCII identifier but is actually spelled
+/// using confusable Unicode characters is a likely source of confusion,
+/// since two visually-identical names can refer to two different objects.
+///
+/// ## Example
+/// ```python
+/// х = 1  # Uses CYRILLIC SMALL LETTER HA (U+0445), not `x`.
+/// ```
+///
+/// Use instead:
+/// ```python
+/// x = 1
+/// ```
+#[violation]
+pub struct AmbiguousUnicodeCharacterIdentifier {
+    confusable: char,
+    representant: char,
+    name: &'static str,
+}
+
+impl AlwaysFixableViolation for AmbiguousUnicodeCharacterIdentifier {
+    #[derive_message_formats]
+    fn message(&self) -> String {
+        let AmbiguousUnicodeCharacterIdentifier {
+            confusable,
+            representant,
+            name,
+        } = self;
+        format!(
+            "Ambiguous unicode character '{name}' (U+{:04X}) looks like '{representant}'",
+            *confusable as u32,
+        )
+    }
+
+    fn fix_title(&self) -> String {
+        let AmbiguousUnicodeCharacterIdentifier { representant, .. } = self;
+        format!("Replace with '{representant}'")
+    }
+}
+
+/// ## What it does
+/// Checks for ambiguous Unicode characters in comments.
+///
+/// ## Why is this bad?
+/// Unicode characters that are confusable with ASCII characters are easy to
+/// introduce by accident (e.g., via copy-paste from a rendered document) and
+/// easy to overlook in review, since they render identically, or nearly
+/// identically, to the character they're being confused with.
+///
+/// ## Example
+/// ```python
+/// # Subtract one: x – 1 (uses an EN DASH (U+2013) instead of a hyphen-minus).
+/// ```
+#[violation]
+pub struct AmbiguousUnicodeCharacterComment {
+    confusable: char,
+    representant: char,
+    name: &'static str,
+}
+
+impl AlwaysFixableViolation for AmbiguousUnicodeCharacterComment {
+    #[derive_message_formats]
+    fn message(&self) -> String {
+        let AmbiguousUnicodeCharacterComment {
+            confusable,
+            representant,
+            name,
+        } = self;
+        format!(
+            "Ambiguous unicode character '{name}' (U+{:04X}) looks like '{representant}'",
+            *confusable as u32,
+        )
+    }
+
+    fn fix_title(&self) -> String {
+        let AmbiguousUnicodeCharacterComment { representant, .. } = self;
+        format!("Replace with '{representant}'")
+    }
+}
+
+/// The kind of token an ambiguous character was found in, which determines which violation (and
+/// noqa code) is reported for it.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(crate) enum Context {
+    String,
+    Identifier,
+    Comment,
+}
+
+/// Checks `range` (the body of a string literal, an identifier, or a comment, as indicated by
+/// `context`) for Unicode characters that are confusable with an ASCII character, and pushes a
+/// diagnostic for each one found onto `diagnostics`.
+///
+/// Characters explicitly allowed via `allowed_confusables` are skipped, and a given confusable
+/// character is only reported once per line, so that (for example) a line of em dashes doesn't
+/// produce a diagnostic per character.
+pub(crate) fn ambiguous_unicode_character(
+    diagnostics: &mut Vec<Diagnostic>,
+    locator: &Locator,
+    range: TextRange,
+    context: Context,
+    allowed_confusables: &FxHashSet<char>,
+) {
+    let text = locator.slice(range);
+
+    let mut seen_on_line: FxHashSet<char> = FxHashSet::default();
+
+    for (relative_offset, current_char) in text.char_indices() {
+        if current_char == '\n' {
+            seen_on_line.clear();
+            continue;
+        }
+
+        let Some((representant, name)) = confusable(current_char) else {
+            continue;
+        };
+        if allowed_confusables.contains(&current_char) {
+            continue;
+        }
+        if !seen_on_line.insert(current_char) {
+            continue;
+        }
+
+        let char_range = TextRange::at(
+            range.start() + TextSize::try_from(relative_offset).unwrap(),
+            current_char.text_len(),
+        );
+        let edit = Edit::range_replacement(representant.to_string(), char_range);
+
+        let diagnostic = match context {
+            Context::String => Diagnostic::new(
+                AmbiguousUnicodeCharacterString {
+                    confusable: current_char,
+                    representant,
+                    name,
+                },
+                char_range,
+            ),
+            Context::Identifier => Diagnostic::new(
+                AmbiguousUnicodeCharacterIdentifier {
+                    confusable: current_char,
+                    representant,
+                    name,
+                },
+                char_range,
+            ),
+            Context::Comment => Diagnostic::new(
+                AmbiguousUnicodeCharacterComment {
+                    confusable: current_char,
+                    representant,
+                    name,
+                },
+                char_range,
+            ),
+        };
+
+        // Replacing the character inside a comment can't change program behavior, so that fix is
+        // safe to apply automatically. Doing so inside a string or bytes literal changes the
+        // literal's runtime value (e.g. a later comparison or serialization could observe the
+        // difference), and doing so inside an identifier can silently collide two previously
+        // distinct names — both require the user to confirm the change.
+        let fix = match context {
+            Context::String | Context::Identifier => Fix::unsafe_edit(edit),
+            Context::Comment => Fix::safe_edit(edit),
+        };
+
+        diagnostics.push(diagnostic.with_fix(fix));
+    }
+}
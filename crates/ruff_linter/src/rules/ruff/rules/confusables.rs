@@ -0,0 +1,70 @@
+/// Returns `Some((ascii, name))` if `c` is a Unicode character that is visually confusable with
+/// the ASCII character `ascii`, where `name` is the human-readable Unicode name of `c` (e.g.
+/// `"GREEK QUESTION MARK"`).
+///
+/// This table is intentionally small: it covers the characters that are both easy to type by
+/// accident (via autocomplete, copy-paste from rendered documents, or an IME) and easy to miss
+/// when reading source code, rather than attempting to enumerate every confusable in the Unicode
+/// Security Mechanisms "confusables" data. It's modeled on the confusable-character table that
+/// rustc's lexer uses to detect the same class of mistakes.
+pub(crate) fn confusable(c: char) -> Option<(char, &'static str)> {
+    CONFUSABLES
+        .binary_search_by_key(&c, |(confusable, _, _)| *confusable)
+        .ok()
+        .map(|index| (CONFUSABLES[index].1, CONFUSABLES[index].2))
+}
+
+/// `(confusable, ascii, unicode name)`, sorted by `confusable` for binary search.
+#[rustfmt::skip]
+const CONFUSABLES: &[(char, char, &str)] = &[
+    ('\u{00a0}', ' ', "NO-BREAK SPACE"),
+    ('\u{037e}', ';', "GREEK QUESTION MARK"),
+    ('\u{0430}', 'a', "CYRILLIC SMALL LETTER A"),
+    ('\u{0435}', 'e', "CYRILLIC SMALL LETTER IE"),
+    ('\u{043e}', 'o', "CYRILLIC SMALL LETTER O"),
+    ('\u{0440}', 'p', "CYRILLIC SMALL LETTER ER"),
+    ('\u{0441}', 'c', "CYRILLIC SMALL LETTER ES"),
+    ('\u{0445}', 'x', "CYRILLIC SMALL LETTER HA"),
+    ('\u{0589}', ':', "ARMENIAN FULL STOP"),
+    ('\u{200b}', ' ', "ZERO WIDTH SPACE"),
+    ('\u{2010}', '-', "HYPHEN"),
+    ('\u{2013}', '-', "EN DASH"),
+    ('\u{2014}', '-', "EM DASH"),
+    ('\u{2018}', '\'', "LEFT SINGLE QUOTATION MARK"),
+    ('\u{2019}', '\'', "RIGHT SINGLE QUOTATION MARK"),
+    ('\u{201c}', '"', "LEFT DOUBLE QUOTATION MARK"),
+    ('\u{201d}', '"', "RIGHT DOUBLE QUOTATION MARK"),
+    ('\u{2024}', '.', "ONE DOT LEADER"),
+    ('\u{2027}', '.', "HYPHENATION POINT"),
+    ('\u{2028}', ' ', "LINE SEPARATOR"),
+    ('\u{2029}', ' ', "PARAGRAPH SEPARATOR"),
+    ('\u{2044}', '/', "FRACTION SLASH"),
+    ('\u{3001}', ',', "IDEOGRAPHIC COMMA"),
+    ('\u{3002}', '.', "IDEOGRAPHIC FULL STOP"),
+    ('\u{ff01}', '!', "FULLWIDTH EXCLAMATION MARK"),
+    ('\u{ff03}', '#', "FULLWIDTH NUMBER SIGN"),
+    ('\u{ff04}', '$', "FULLWIDTH DOLLAR SIGN"),
+    ('\u{ff05}', '%', "FULLWIDTH PERCENT SIGN"),
+    ('\u{ff06}', '&', "FULLWIDTH AMPERSAND"),
+    ('\u{ff08}', '(', "FULLWIDTH LEFT PARENTHESIS"),
+    ('\u{ff09}', ')', "FULLWIDTH RIGHT PARENTHESIS"),
+    ('\u{ff0c}', ',', "FULLWIDTH COMMA"),
+    ('\u{ff0e}', '.', "FULLWIDTH FULL STOP"),
+    ('\u{ff1a}', ':', "FULLWIDTH COLON"),
+    ('\u{ff1b}', ';', "FULLWIDTH SEMICOLON"),
+    ('\u{ff1d}', '=', "FULLWIDTH EQUALS SIGN"),
+    ('\u{ff3b}', '[', "FULLWIDTH LEFT SQUARE BRACKET"),
+    ('\u{ff3d}', ']', "FULLWIDTH RIGHT SQUARE BRACKET"),
+    ('\u{ff5b}', '{', "FULLWIDTH LEFT CURLY BRACKET"),
+    ('\u{ff5d}', '}', "FULLWIDTH RIGHT CURLY BRACKET"),
+];
+
+#[cfg(test)]
+mod tests {
+    use super::CONFUSABLES;
+
+    #[test]
+    fn table_is_sorted_by_confusable_char() {
+        assert!(CONFUSABLES.windows(2).all(|pair| pair[0].0 < pair[1].0));
+    }
+}
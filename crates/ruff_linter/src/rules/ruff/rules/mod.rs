@@ -0,0 +1,4 @@
+pub(crate) use ambiguous_unicode_character::*;
+
+mod ambiguous_unicode_character;
+mod confusables;